@@ -1,6 +1,8 @@
 use crate::prelude::*;
 use std::borrow::Cow;
 use std::collections::HashMap;
+#[cfg(feature = "zip")]
+use std::collections::HashSet;
 
 /// A generic MIME Entity.
 #[derive(Debug, PartialEq, Clone)]
@@ -27,6 +29,19 @@ impl<'a> RawEntity<'a> {
     pub fn parse(self) -> Result<Entity<'a>, Error> {
         crate::parsing::mime::entity::entity(self)
     }
+
+    /// Recursively walks this entity, and, if it is a multipart, its
+    /// children, collecting every [Attachment]: an entity whose
+    /// [Disposition::disposition_type] is [DispositionType::Attachment] or
+    /// [DispositionType::Unknown], or that carries a `filename`.\
+    /// This is a one-call alternative to manually recursing
+    /// [Entity::Multipart] and calling [RawEntity::parse] on each child.
+    #[cfg(feature = "content-disposition")]
+    pub fn attachments(self) -> Result<Vec<Attachment<'a>>, Error> {
+        let mut attachments = Vec::new();
+        collect_attachments(self, &mut attachments)?;
+        Ok(attachments)
+    }
 }
 
 /// A higher-level reprentation of entities.\
@@ -49,6 +64,25 @@ pub enum Entity<'a> {
     Unknown(Box<RawEntity<'a>>),
 }
 
+impl<'a> Entity<'a> {
+    /// Recursively walks this entity's [Entity::Multipart] children (if
+    /// any), collecting every [Attachment]. See [RawEntity::attachments].
+    #[cfg(feature = "content-disposition")]
+    pub fn attachments(self) -> Result<Vec<Attachment<'a>>, Error> {
+        let mut attachments = Vec::new();
+        match self {
+            Entity::Multipart { content, .. } => {
+                for child in content {
+                    collect_attachments(child, &mut attachments)?;
+                }
+            }
+            Entity::Text { .. } => {}
+            Entity::Unknown(raw) => attachments.extend((*raw).attachments()?),
+        }
+        Ok(attachments)
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum MimeType<'a> {
     // Fixme: rename to ContentType
@@ -182,3 +216,291 @@ impl<'a> ContentTransferEncoding<'a> {
         }
     }
 }
+
+/// An attachment extracted from an [Entity]/[RawEntity] tree by
+/// [RawEntity::attachments] or [Entity::attachments].
+#[cfg(feature = "content-disposition")]
+#[derive(Debug, PartialEq, Clone)]
+pub struct Attachment<'a> {
+    pub filename: Cow<'a, str>,
+    pub mime_type: MimeType<'a>,
+    /// The subtype (in lowercase).
+    pub subtype: Cow<'a, str>,
+    pub creation_date: Option<DateTime>,
+    pub modification_date: Option<DateTime>,
+    /// The raw value of this attachment. It has already been decoded.
+    pub value: Cow<'a, [u8]>,
+}
+
+#[cfg(feature = "content-disposition")]
+impl<'a> Attachment<'a> {
+    pub fn into_owned(self) -> Attachment<'static> {
+        Attachment {
+            filename: Cow::Owned(self.filename.into_owned()),
+            mime_type: self.mime_type.into_owned(),
+            subtype: Cow::Owned(self.subtype.into_owned()),
+            creation_date: self.creation_date,
+            modification_date: self.modification_date,
+            value: Cow::Owned(self.value.into_owned()),
+        }
+    }
+}
+
+#[cfg(feature = "content-disposition")]
+fn collect_attachments<'a>(
+    raw: RawEntity<'a>,
+    attachments: &mut Vec<Attachment<'a>>,
+) -> Result<(), Error> {
+    let is_attachment = raw.disposition.as_ref().map_or(false, |disposition| {
+        matches!(
+            disposition.disposition_type,
+            DispositionType::Attachment | DispositionType::Unknown(_)
+        ) || disposition.filename.is_some()
+    });
+
+    if is_attachment {
+        let filename = raw
+            .disposition
+            .as_ref()
+            .and_then(|disposition| disposition.filename.clone())
+            .unwrap_or_else(|| fallback_filename(&raw, attachments.len()));
+        let (creation_date, modification_date) = raw
+            .disposition
+            .as_ref()
+            .map(|disposition| (disposition.creation_date, disposition.modification_date))
+            .unwrap_or((None, None));
+        attachments.push(Attachment {
+            filename,
+            mime_type: raw.mime_type,
+            subtype: raw.subtype,
+            creation_date,
+            modification_date,
+            value: raw.value,
+        });
+        return Ok(());
+    }
+
+    if raw.mime_type == MimeType::Multipart {
+        if let Entity::Multipart { content, .. } = raw.parse()? {
+            for child in content {
+                collect_attachments(child, attachments)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// A fallback filename for an attachment with no `filename` parameter,
+/// e.g. `attachment-0.pdf`.
+#[cfg(feature = "content-disposition")]
+fn fallback_filename<'a>(raw: &RawEntity<'a>, index: usize) -> Cow<'a, str> {
+    Cow::Owned(format!("attachment-{}.{}", index, raw.subtype))
+}
+
+/// Streams a set of [Attachment]s into a ZIP archive, deriving each entry's
+/// modification time from its [Disposition] creation/modification date (if
+/// any) and deduplicating collisions between attachment filenames.
+#[cfg(feature = "zip")]
+pub fn write_attachments_zip<W: std::io::Write + std::io::Seek>(
+    attachments: &[Attachment<'_>],
+    writer: W,
+) -> Result<(), Error> {
+    let mut zip = zip::ZipWriter::new(writer);
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut emitted: HashSet<String> = HashSet::new();
+
+    for attachment in attachments {
+        let name = dedup_filename(&attachment.filename, &mut seen, &mut emitted);
+
+        let mut options = zip::write::FileOptions::default();
+        if let Some(mtime) = attachment_mtime(attachment) {
+            options = options.last_modified_time(mtime);
+        }
+
+        zip.start_file(name, options)
+            .map_err(|_e| Error::Known("failed to start zip entry"))?;
+        zip.write_all(&attachment.value)
+            .map_err(|_e| Error::Known("failed to write zip entry"))?;
+    }
+
+    zip.finish()
+        .map_err(|_e| Error::Known("failed to finalize zip archive"))?;
+    Ok(())
+}
+
+#[cfg(feature = "zip")]
+fn attachment_mtime(attachment: &Attachment<'_>) -> Option<zip::DateTime> {
+    let date_time = attachment
+        .modification_date
+        .as_ref()
+        .or(attachment.creation_date.as_ref())?;
+    let (_day, (day, month, year), ((hour, minute, second, _nanosecond), _zone)) = date_time;
+    zip::DateTime::from_date_and_time(
+        *year as u16,
+        month.number(),
+        *day as u8,
+        *hour,
+        *minute,
+        *second,
+    )
+    .ok()
+}
+
+/// Finds a name for `filename` that hasn't already been emitted, bumping a
+/// per-original-filename counter until the candidate is free.\
+/// Tracking against `emitted` (not just `seen`'s per-input counts) is what
+/// prevents a generated `"name (1).ext"` from colliding with an identical
+/// literal input later in the list.
+#[cfg(feature = "zip")]
+fn dedup_filename(
+    filename: &str,
+    seen: &mut HashMap<String, usize>,
+    emitted: &mut HashSet<String>,
+) -> String {
+    let count = seen.entry(filename.to_owned()).or_insert(0);
+    loop {
+        let candidate = if *count == 0 {
+            filename.to_owned()
+        } else {
+            match filename.rsplit_once('.') {
+                Some((stem, extension)) => format!("{} ({}).{}", stem, *count, extension),
+                None => format!("{} ({})", filename, *count),
+            }
+        };
+        *count += 1;
+        if emitted.insert(candidate.clone()) {
+            return candidate;
+        }
+    }
+}
+#[cfg(all(test, feature = "content-disposition"))]
+mod test {
+    use super::*;
+
+    fn disposition<'a>(
+        disposition_type: DispositionType<'a>,
+        filename: Option<&'a str>,
+    ) -> Disposition<'a> {
+        Disposition {
+            disposition_type,
+            filename: filename.map(Cow::Borrowed),
+            creation_date: None,
+            modification_date: None,
+            read_date: None,
+            unstructured: HashMap::new(),
+        }
+    }
+
+    fn raw_entity<'a>(
+        mime_type: MimeType<'a>,
+        subtype: &'a str,
+        parameters: HashMap<Cow<'a, str>, Cow<'a, str>>,
+        disposition: Option<Disposition<'a>>,
+        value: &'a [u8],
+    ) -> RawEntity<'a> {
+        RawEntity {
+            mime_type,
+            subtype: Cow::Borrowed(subtype),
+            description: None,
+            id: None,
+            parameters,
+            disposition,
+            value: Cow::Borrowed(value),
+            additional_headers: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_disposition_unknown_is_attachment() {
+        let raw = raw_entity(
+            MimeType::Text,
+            "plain",
+            HashMap::new(),
+            Some(disposition(
+                DispositionType::Unknown(Cow::Borrowed("x-custom")),
+                Some("notes.txt"),
+            )),
+            b"hello",
+        );
+
+        let attachments = raw.attachments().unwrap();
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].filename, "notes.txt");
+    }
+
+    #[test]
+    fn test_non_attachment_entity_yields_nothing() {
+        let raw = raw_entity(MimeType::Text, "plain", HashMap::new(), None, b"hello");
+        assert_eq!(raw.attachments().unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn test_fallback_filename_used_when_none_given() {
+        let raw = raw_entity(
+            MimeType::Application,
+            "pdf",
+            HashMap::new(),
+            Some(disposition(DispositionType::Attachment, None)),
+            b"%PDF-1.4",
+        );
+
+        let attachments = raw.attachments().unwrap();
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].filename, "attachment-0.pdf");
+    }
+
+    #[test]
+    fn test_recursive_multipart_descent() {
+        let leaf = raw_entity(
+            MimeType::Application,
+            "pdf",
+            HashMap::new(),
+            Some(disposition(DispositionType::Attachment, Some("report.pdf"))),
+            b"%PDF-1.4",
+        );
+        let nested_body =
+            b"--inner\r\nContent-Type: application/pdf\r\nContent-Disposition: attachment; filename=\"report.pdf\"\r\n\r\n%PDF-1.4\r\n--inner--\r\n";
+        let mut nested_parameters = HashMap::new();
+        nested_parameters.insert(Cow::Borrowed("boundary"), Cow::Borrowed("inner"));
+        let nested = raw_entity(
+            MimeType::Multipart,
+            "mixed",
+            nested_parameters,
+            None,
+            nested_body,
+        );
+        let text = raw_entity(MimeType::Text, "plain", HashMap::new(), None, b"hello");
+
+        let outer = Entity::Multipart {
+            subtype: Cow::Borrowed("mixed"),
+            content: vec![text, nested, leaf],
+        };
+
+        let attachments = outer.attachments().unwrap();
+        let filenames: Vec<_> = attachments.iter().map(|a| a.filename.as_ref()).collect();
+        assert_eq!(filenames, vec!["report.pdf", "report.pdf"]);
+    }
+
+    #[cfg(feature = "zip")]
+    #[test]
+    fn test_dedup_filename_does_not_reuse_generated_names() {
+        let mut seen = HashMap::new();
+        let mut emitted = HashSet::new();
+
+        assert_eq!(
+            dedup_filename("invoice.pdf", &mut seen, &mut emitted),
+            "invoice.pdf"
+        );
+        assert_eq!(
+            dedup_filename("invoice.pdf", &mut seen, &mut emitted),
+            "invoice (1).pdf"
+        );
+        // A literal input that happens to match a name already generated
+        // above must not be re-emitted as-is.
+        assert_eq!(
+            dedup_filename("invoice (1).pdf", &mut seen, &mut emitted),
+            "invoice (1) (1).pdf"
+        );
+    }
+}
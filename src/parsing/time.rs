@@ -1,7 +1,33 @@
 use crate::prelude::*;
+#[cfg(feature = "chrono")]
+use chrono::TimeZone;
+
+/// A parsed RFC 5322 zone.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Zone {
+    /// A definite offset from UTC (`sign`, `hours`, `minutes`), e.g. `+0100` or `-0500`.
+    Offset(bool, u8, u8),
+    /// The obsolete `-0000`/unknown zone (RFC 5322 §4.3): the time should be
+    /// treated as UTC, but the offset itself is not actually known. This is
+    /// what every military zone other than `Z` must be parsed as, since
+    /// their meanings are unreliable in practice.
+    Unknown,
+}
+
+impl Zone {
+    /// The `(sign, hours, minutes)` offset to apply to normalize to UTC,
+    /// treating [Zone::Unknown] as `+0000`.
+    pub fn offset(&self) -> (bool, u8, u8) {
+        match self {
+            Zone::Offset(sign, hours, minutes) => (*sign, *hours, *minutes),
+            Zone::Unknown => (true, 0, 0),
+        }
+    }
+}
 
-pub type Zone = (bool, u8, u8);
-pub type Time = ((u8, u8, u8), Zone);
+/// `(hour, minute, second, nanosecond)` plus the parsed [Zone].\
+/// `nanosecond` is `0` unless a fractional-seconds component was present.
+pub type Time = ((u8, u8, u8, u32), Zone);
 pub type Date = (usize, Month, usize);
 pub type DateTime = (Option<Day>, Date, Time);
 
@@ -84,27 +110,58 @@ pub fn day_of_week(input: &[u8]) -> Res<Day> {
     Ok((input, day))
 }
 
+/// Parses an RFC 5322 `year`, tolerating the obsolete 2- and 3-digit
+/// `obs-year` forms found in archived mail: per §4.3, a 2-digit year in
+/// `00`-`49` maps to `2000`-`2049`, `50`-`99` maps to `1950`-`1999`, and a
+/// 3-digit year `n` maps to `1900 + n`. Use [year_strict] to reject these
+/// and require the modern 4-digit form.
 pub fn year(input: &[u8]) -> Res<usize> {
     let (input, _) = fws(input)?;
 
-    let (input, year) =
+    let (input, digits) =
         take_while1(input, is_digit).map_err(|_e| Error::Known("no digit in year"))?;
-    if year.len() < 4 {
-        return Err(Error::Known("year is expected to have 4 digits or more"));
+    if digits.len() < 2 {
+        return Err(Error::Known("year is expected to have 2 digits or more"));
     }
-    let year: usize = as_str(&year)
+    let year: usize = as_str(&digits)
         .parse()
         .map_err(|_e| Error::Known("Failed to parse year"))?;
+    let year = normalize_obs_year(digits.len(), year);
+
+    let (input, _) = fws(input)?;
 
-    if year < 1990 {
-        return Err(Error::Known("year must be after 1990"));
+    Ok((input, year))
+}
+
+/// Like [year], but rejects the obsolete 2- and 3-digit `obs-year` forms:
+/// only a 4-digit (or longer) year is accepted.
+pub fn year_strict(input: &[u8]) -> Res<usize> {
+    let (input, _) = fws(input)?;
+
+    let (input, digits) =
+        take_while1(input, is_digit).map_err(|_e| Error::Known("no digit in year"))?;
+    if digits.len() < 4 {
+        return Err(Error::Known("year is expected to have 4 digits or more"));
     }
+    let year: usize = as_str(&digits)
+        .parse()
+        .map_err(|_e| Error::Known("Failed to parse year"))?;
 
     let (input, _) = fws(input)?;
 
     Ok((input, year))
 }
 
+/// Normalizes an RFC 5322 `obs-year` to a 4-digit (or more) year, per §4.3.
+fn normalize_obs_year(num_digits: usize, year: usize) -> usize {
+    match num_digits {
+        2 if year <= 49 => 2000 + year,
+        2 => 1900 + year,
+        3 => 1900 + year,
+        _ => year,
+    }
+}
+
 pub fn day(input: &[u8]) -> Res<usize> {
     let (input, _fws) = optional(input, fws);
     let (mut input, mut day) = digit(input)?;
@@ -120,7 +177,10 @@ pub fn day(input: &[u8]) -> Res<usize> {
     Ok((input, day as usize))
 }
 
-pub fn time_of_day(input: &[u8]) -> Res<(u8, u8, u8)> {
+/// Parses an RFC 5322 `time-of-day`, additionally accepting a trailing
+/// fractional-seconds component (`.` followed by one or more digits, as
+/// seen in ISO-style timestamps) and returning it as nanoseconds.
+pub fn time_of_day(input: &[u8]) -> Res<(u8, u8, u8, u32)> {
     let (input, hour) = two_digits(input)?;
     if hour > 23 {
         return Err(Error::Known("There is only 24 hours in a day"));
@@ -139,32 +199,87 @@ pub fn time_of_day(input: &[u8]) -> Res<(u8, u8, u8)> {
                 // leap second allowed
                 return Err(Error::Known("There is only 60 seconds in a minute"));
             }
-            return Ok((new_input, (hour, minutes, seconds)));
+            let (new_input, nanosecond) = optional_fraction(new_input)?;
+            return Ok((new_input, (hour, minutes, seconds, nanosecond)));
         }
     }
 
-    Ok((input, (hour, minutes, 0)))
+    let (input, nanosecond) = optional_fraction(input)?;
+    Ok((input, (hour, minutes, 0, nanosecond)))
+}
+
+/// Parses an optional `.` followed by one or more digits, returning the
+/// fraction as nanoseconds (parsing up to 9 digits, truncating the rest).
+fn optional_fraction(input: &[u8]) -> Res<u32> {
+    if !input.starts_with(b".") {
+        return Ok((input, 0));
+    }
+
+    let (input, digits) = take_while1(&input[1..], is_digit)
+        .map_err(|_e| Error::Known("expected digits after '.' in fractional seconds"))?;
+
+    let truncated = if digits.len() > 9 { &digits[..9] } else { digits };
+    let value: u32 = as_str(truncated)
+        .parse()
+        .map_err(|_e| Error::Known("Failed to parse fractional seconds"))?;
+    let nanosecond = value * 10u32.pow(9 - truncated.len() as u32);
+
+    Ok((input, nanosecond))
 }
 
 pub fn zone(input: &[u8]) -> Res<Zone> {
-    let (mut input, _fws) = fws(input)?;
+    let (input, _fws) = fws(input)?;
 
-    let sign = match input.get(0) {
-        Some(b'+') => true,
-        Some(b'-') => false,
-        None => return Err(Error::Known("Expected more characters in zone")),
-        _ => return Err(Error::Known("Invalid sign character in zone")),
-    };
-    input = &input[1..];
+    match input.get(0) {
+        Some(b'+') | Some(b'-') => {
+            let sign = input[0] == b'+';
+            let input = &input[1..];
 
-    let (input, hours) = two_digits(input)?;
-    let (input, minutes) = two_digits(input)?;
+            let (input, hours) = two_digits(input)?;
+            let (input, minutes) = two_digits(input)?;
 
-    if minutes > 59 {
-        return Err(Error::Known("zone minutes out of range"));
+            if minutes > 59 {
+                return Err(Error::Known("zone minutes out of range"));
+            }
+
+            // RFC 5322 §4.3: a literal `-0000` is the "unknown offset"
+            // marker, not a real zero offset.
+            if !sign && hours == 0 && minutes == 0 {
+                return Ok((input, Zone::Unknown));
+            }
+
+            Ok((input, Zone::Offset(sign, hours, minutes)))
+        }
+        Some(_) => obs_zone(input),
+        None => Err(Error::Known("Expected more characters in zone")),
     }
+}
+
+/// Parses an RFC 5322 `obs-zone`: `UT`/`GMT`/`Z`, the named civilian zones
+/// (`EST`, `EDT`, ... `PDT`), and the single-letter military zones.
+///
+/// Per RFC 5322 §4.3 every alphabetic zone other than the recognized
+/// civilian ones (including every military zone, which is ambiguous in
+/// practice) must be treated as [Zone::Unknown] rather than rejected, so
+/// `date_time` keeps parsing archived/obsolete mail.
+fn obs_zone(input: &[u8]) -> Res<Zone> {
+    let (input, letters) =
+        take_while1(input, |c: u8| c.is_ascii_alphabetic()).map_err(|_e| Error::Known("zone"))?;
+
+    let zone = match letters.to_ascii_uppercase().as_slice() {
+        b"UT" | b"GMT" | b"Z" => Zone::Offset(true, 0, 0),
+        b"EST" => Zone::Offset(false, 5, 0),
+        b"EDT" => Zone::Offset(false, 4, 0),
+        b"CST" => Zone::Offset(false, 6, 0),
+        b"CDT" => Zone::Offset(false, 5, 0),
+        b"MST" => Zone::Offset(false, 7, 0),
+        b"MDT" => Zone::Offset(false, 6, 0),
+        b"PST" => Zone::Offset(false, 8, 0),
+        b"PDT" => Zone::Offset(false, 7, 0),
+        _ => Zone::Unknown,
+    };
 
-    Ok((input, (sign, hours, minutes)))
+    Ok((input, zone))
 }
 
 pub fn time(input: &[u8]) -> Res<Time> {
@@ -188,6 +303,201 @@ pub fn date_time(input: &[u8]) -> Res<DateTime> {
     Ok((input, (day, date, time)))
 }
 
+impl Month {
+    /// The 1-indexed month number (`January` is `1`).
+    pub fn number(&self) -> u8 {
+        match self {
+            Month::January => 1,
+            Month::February => 2,
+            Month::March => 3,
+            Month::April => 4,
+            Month::May => 5,
+            Month::June => 6,
+            Month::July => 7,
+            Month::August => 8,
+            Month::September => 9,
+            Month::October => 10,
+            Month::November => 11,
+            Month::December => 12,
+        }
+    }
+
+    /// The 3-letter RFC 5322 `month-name`, e.g. `"Apr"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Month::January => "Jan",
+            Month::February => "Feb",
+            Month::March => "Mar",
+            Month::April => "Apr",
+            Month::May => "May",
+            Month::June => "Jun",
+            Month::July => "Jul",
+            Month::August => "Aug",
+            Month::September => "Sep",
+            Month::October => "Oct",
+            Month::November => "Nov",
+            Month::December => "Dec",
+        }
+    }
+}
+
+impl Day {
+    /// The 3-letter RFC 5322 `day-name`, e.g. `"Mon"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Day::Monday => "Mon",
+            Day::Tuesday => "Tue",
+            Day::Wednesday => "Wed",
+            Day::Thursday => "Thu",
+            Day::Friday => "Fri",
+            Day::Saturday => "Sat",
+            Day::Sunday => "Sun",
+        }
+    }
+}
+
+/// Howard Hinnant's days-from-civil algorithm: the number of days since
+/// 1970-01-01 for the given proleptic Gregorian date.
+/// See <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400; // [0, 399]
+    let doy = (153 * (i64::from(month) + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(day) - 1; // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+    era * 146097 + doe - 719468
+}
+
+fn day_from_index(index: i64) -> Day {
+    match index {
+        0 => Day::Sunday,
+        1 => Day::Monday,
+        2 => Day::Tuesday,
+        3 => Day::Wednesday,
+        4 => Day::Thursday,
+        5 => Day::Friday,
+        _ => Day::Saturday,
+    }
+}
+
+/// Converts a parsed [DateTime] into a Unix timestamp (seconds since
+/// 1970-01-01T00:00:00Z), normalizing the zone offset to UTC.
+pub fn to_timestamp(date_time: &DateTime) -> i64 {
+    let (_day, (day, month, year), ((hour, minute, second, _nanosecond), zone)) = date_time;
+    let (sign, zone_hours, zone_minutes) = zone.offset();
+    let days = days_from_civil(*year as i64, month.number(), *day as u8);
+    let seconds =
+        days * 86_400 + i64::from(*hour) * 3600 + i64::from(*minute) * 60 + i64::from(*second);
+    let offset = i64::from(zone_hours) * 3600 + i64::from(zone_minutes) * 60;
+    if sign {
+        seconds - offset
+    } else {
+        seconds + offset
+    }
+}
+
+/// The weekday of a parsed [DateTime], derived from its date rather than the
+/// (optional) parsed [Day], so callers can cross-check the two.
+pub fn weekday(date_time: &DateTime) -> Day {
+    let (_day, (day, month, year), _time) = date_time;
+    let days = days_from_civil(*year as i64, month.number(), *day as u8);
+    day_from_index((days + 4).rem_euclid(7))
+}
+
+#[cfg(feature = "time")]
+/// Converts a parsed [DateTime] into a [time::OffsetDateTime].
+pub fn to_offset_date_time(date_time: &DateTime) -> Result<time::OffsetDateTime, Error> {
+    let (_day, _date, ((_hour, _minute, _second, nanosecond), zone)) = date_time;
+    let (sign, zone_hours, zone_minutes) = zone.offset();
+    let zone_hours = if sign { zone_hours as i8 } else { -(zone_hours as i8) };
+    let zone_minutes = if sign { zone_minutes as i8 } else { -(zone_minutes as i8) };
+    let offset = time::UtcOffset::from_hms(zone_hours, zone_minutes, 0)
+        .map_err(|_e| Error::Known("zone offset is out of range"))?;
+    time::OffsetDateTime::from_unix_timestamp(to_timestamp(date_time))
+        .map(|date_time| {
+            (date_time + time::Duration::nanoseconds(i64::from(*nanosecond))).to_offset(offset)
+        })
+        .map_err(|_e| Error::Known("timestamp is out of range for OffsetDateTime"))
+}
+
+#[cfg(feature = "chrono")]
+/// Converts a parsed [DateTime] into a [chrono::DateTime<chrono::FixedOffset>].
+pub fn to_chrono_date_time(
+    date_time: &DateTime,
+) -> Result<chrono::DateTime<chrono::FixedOffset>, Error> {
+    let (_day, _date, ((_hour, _minute, _second, nanosecond), zone)) = date_time;
+    let (sign, zone_hours, zone_minutes) = zone.offset();
+    let offset_seconds = i32::from(zone_hours) * 3600 + i32::from(zone_minutes) * 60;
+    let offset_seconds = if sign { offset_seconds } else { -offset_seconds };
+    let offset = chrono::FixedOffset::east_opt(offset_seconds)
+        .ok_or(Error::Known("zone offset is out of range"))?;
+    chrono::Utc
+        .timestamp_opt(to_timestamp(date_time), *nanosecond)
+        .single()
+        .ok_or(Error::Known("timestamp is out of range for DateTime"))
+        .map(|date_time| date_time.with_timezone(&offset))
+}
+
+/// Renders a parsed [Zone] as its `(sign, hours, minutes)` numeric offset,
+/// using `-0000` for [Zone::Unknown] per RFC 5322 §4.3.
+fn zone_sign_offset(zone: &Zone) -> (char, u8, u8) {
+    match zone {
+        Zone::Offset(sign, hours, minutes) => (if *sign { '+' } else { '-' }, *hours, *minutes),
+        Zone::Unknown => ('-', 0, 0),
+    }
+}
+
+/// Renders a parsed [DateTime] as a canonical RFC 2822 date-time string,
+/// e.g. `Mon, 12 Apr 2023 10:25:03 +0000`. The `Day` is taken from the
+/// parsed value if present, or computed with [weekday] otherwise.
+pub fn to_rfc2822(date_time: &DateTime) -> String {
+    let (day, (day_num, month, year), ((hour, minute, second, _nanosecond), zone)) = date_time;
+    let day_name = match day {
+        Some(day) => day.name(),
+        None => weekday(date_time).name(),
+    };
+    let (sign, zone_hours, zone_minutes) = zone_sign_offset(zone);
+    format!(
+        "{}, {:02} {} {:04} {:02}:{:02}:{:02} {}{:02}{:02}",
+        day_name,
+        day_num,
+        month.name(),
+        year,
+        hour,
+        minute,
+        second,
+        sign,
+        zone_hours,
+        zone_minutes
+    )
+}
+
+/// Renders a parsed [DateTime] as an ISO 8601 / RFC 3339 string, e.g.
+/// `2023-04-12T10:25:03+00:00` or, with sub-second precision,
+/// `2023-04-12T10:25:03.5+00:00`.
+pub fn to_iso8601(date_time: &DateTime) -> String {
+    let (_day, (day_num, month, year), ((hour, minute, second, nanosecond), zone)) = date_time;
+    let (sign, zone_hours, zone_minutes) = zone_sign_offset(zone);
+    let fraction = if *nanosecond > 0 {
+        format!(".{}", format!("{:09}", nanosecond).trim_end_matches('0'))
+    } else {
+        String::new()
+    };
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}{}{:02}:{:02}",
+        year,
+        month.number(),
+        day_num,
+        hour,
+        minute,
+        second,
+        fraction,
+        sign,
+        zone_hours,
+        zone_minutes
+    )
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -219,6 +529,23 @@ mod test {
         assert_eq!(year(b" 250032 ").unwrap().1, 250032);
     }
 
+    #[test]
+    fn test_obs_year() {
+        // obs-year: 2-digit years split at 50, 3-digit years add 1900.
+        assert_eq!(year(b" 20 ").unwrap().1, 2020);
+        assert_eq!(year(b" 49 ").unwrap().1, 2049);
+        assert_eq!(year(b" 50 ").unwrap().1, 1950);
+        assert_eq!(year(b" 99 ").unwrap().1, 1999);
+        assert_eq!(year(b" 995 ").unwrap().1, 2895);
+        assert_eq!(year(b" 2020 ").unwrap().1, 2020);
+
+        assert!(year_strict(b" 99 ").is_err());
+        assert_eq!(year_strict(b" 1999 ").unwrap().1, 1999);
+
+        // A single digit isn't a valid obs-year.
+        assert!(year(b" 5 ").is_err());
+    }
+
     #[test]
     fn test_date() {
         assert_eq!(date(b"1 nov 2020 ").unwrap().1, (1, Month::November, 2020));
@@ -232,29 +559,145 @@ mod test {
             (
                 Some(Day::Monday),
                 (12, Month::April, 2023),
-                ((10, 25, 3), (true, 0, 0))
+                ((10, 25, 3, 0), Zone::Offset(true, 0, 0))
             )
         );
         assert_eq!(
             date_time(b"5 May 2003 18:59:03 +0000").unwrap().1,
-            (None, (5, Month::May, 2003), ((18, 59, 3), (true, 0, 0)))
+            (
+                None,
+                (5, Month::May, 2003),
+                ((18, 59, 3, 0), Zone::Offset(true, 0, 0))
+            )
         );
     }
 
     #[test]
     fn test_time() {
-        assert_eq!(time_of_day(b"10:40:29").unwrap().1, (10, 40, 29));
-        assert_eq!(time_of_day(b"10:40 ").unwrap().1, (10, 40, 0));
-        assert_eq!(time_of_day(b"05:23 ").unwrap().1, (5, 23, 0));
+        assert_eq!(time_of_day(b"10:40:29").unwrap().1, (10, 40, 29, 0));
+        assert_eq!(time_of_day(b"10:40 ").unwrap().1, (10, 40, 0, 0));
+        assert_eq!(time_of_day(b"05:23 ").unwrap().1, (5, 23, 0, 0));
 
-        assert_eq!(zone(b" +1000 ").unwrap().1, (true, 10, 0));
-        assert_eq!(zone(b" -0523 ").unwrap().1, (false, 5, 23));
+        assert_eq!(zone(b" +1000 ").unwrap().1, Zone::Offset(true, 10, 0));
+        assert_eq!(zone(b" -0523 ").unwrap().1, Zone::Offset(false, 5, 23));
 
-        assert_eq!(time(b"06:44 +0100").unwrap().1, ((6, 44, 0), (true, 1, 0)));
-        assert_eq!(time(b"23:57 +0000").unwrap().1, ((23, 57, 0), (true, 0, 0)));
+        assert_eq!(
+            time(b"06:44 +0100").unwrap().1,
+            ((6, 44, 0, 0), Zone::Offset(true, 1, 0))
+        );
+        assert_eq!(
+            time(b"23:57 +0000").unwrap().1,
+            ((23, 57, 0, 0), Zone::Offset(true, 0, 0))
+        );
         assert_eq!(
             time(b"08:23:02 -0500").unwrap().1,
-            ((8, 23, 2), (false, 5, 0))
+            ((8, 23, 2, 0), Zone::Offset(false, 5, 0))
         );
     }
+
+    #[test]
+    fn test_fractional_seconds() {
+        assert_eq!(
+            time_of_day(b"10:40:29.5").unwrap().1,
+            (10, 40, 29, 500_000_000)
+        );
+        assert_eq!(
+            time_of_day(b"10:40:29.123456789").unwrap().1,
+            (10, 40, 29, 123_456_789)
+        );
+        // truncated to 9 digits
+        assert_eq!(
+            time_of_day(b"10:40:29.1234567891").unwrap().1,
+            (10, 40, 29, 123_456_789)
+        );
+        // no seconds component, still allowed without a fraction
+        assert_eq!(time_of_day(b"10:40 ").unwrap().1, (10, 40, 0, 0));
+
+        assert_eq!(
+            time(b"10:25:03.5 Z").unwrap().1,
+            ((10, 25, 3, 500_000_000), Zone::Offset(true, 0, 0))
+        );
+        assert_eq!(
+            time(b"10:25:03.5 z").unwrap().1,
+            ((10, 25, 3, 500_000_000), Zone::Offset(true, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_obs_zone() {
+        assert_eq!(zone(b" UT").unwrap().1, Zone::Offset(true, 0, 0));
+        assert_eq!(zone(b" GMT").unwrap().1, Zone::Offset(true, 0, 0));
+        assert_eq!(zone(b" Z").unwrap().1, Zone::Offset(true, 0, 0));
+        assert_eq!(zone(b" EST").unwrap().1, Zone::Offset(false, 5, 0));
+        assert_eq!(zone(b" PDT").unwrap().1, Zone::Offset(false, 7, 0));
+
+        // Military zones (including the unused `J`) are ambiguous in
+        // practice and must be parsed as unknown per RFC 5322 §4.3.
+        assert_eq!(zone(b" A").unwrap().1, Zone::Unknown);
+        assert_eq!(zone(b" J").unwrap().1, Zone::Unknown);
+        assert_eq!(zone(b" N").unwrap().1, Zone::Unknown);
+        assert_eq!(zone(b" Y").unwrap().1, Zone::Unknown);
+
+        assert_eq!(zone(b" GIBBERISH").unwrap().1, Zone::Unknown);
+
+        // A literal `-0000` is the RFC 5322 §4.3 "unknown offset" marker,
+        // distinct from the real `+0000`.
+        assert_eq!(zone(b" -0000").unwrap().1, Zone::Unknown);
+        assert_eq!(zone(b" +0000").unwrap().1, Zone::Offset(true, 0, 0));
+    }
+
+    #[test]
+    fn test_to_timestamp() {
+        let parsed = date_time(b"Wed, 12 Apr 2023 10:25:03 +0000").unwrap().1;
+        assert_eq!(to_timestamp(&parsed), 1681295103);
+        assert_eq!(weekday(&parsed), Day::Wednesday);
+
+        let parsed = date_time(b"Thu, 1 Jan 1970 00:00:00 +0000").unwrap().1;
+        assert_eq!(to_timestamp(&parsed), 0);
+        assert_eq!(weekday(&parsed), Day::Thursday);
+
+        let parsed = date_time(b"5 May 2003 18:59:03 -0500").unwrap().1;
+        assert_eq!(to_timestamp(&parsed), 1052179143);
+    }
+
+    #[test]
+    fn test_serialize_round_trip() {
+        let parsed = date_time(b"Mon, 12 Apr 2023 10:25:03 +0000").unwrap().1;
+        assert_eq!(to_rfc2822(&parsed), "Mon, 12 Apr 2023 10:25:03 +0000");
+        assert_eq!(to_iso8601(&parsed), "2023-04-12T10:25:03+00:00");
+        assert_eq!(
+            date_time(to_rfc2822(&parsed).as_bytes()).unwrap().1,
+            parsed
+        );
+
+        // No parsed Day: the weekday is computed instead.
+        let parsed = date_time(b"5 May 2003 18:59:03 -0500").unwrap().1;
+        assert_eq!(to_rfc2822(&parsed), "Mon, 05 May 2003 18:59:03 -0500");
+        assert_eq!(to_iso8601(&parsed), "2003-05-05T18:59:03-05:00");
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_to_offset_date_time() {
+        let parsed = date_time(b"5 May 2003 18:59:03 -0500").unwrap().1;
+        let offset_date_time = to_offset_date_time(&parsed).unwrap();
+        assert_eq!(
+            offset_date_time.offset(),
+            time::UtcOffset::from_hms(-5, 0, 0).unwrap()
+        );
+        assert_eq!(offset_date_time.hour(), 18);
+        assert_eq!(offset_date_time.unix_timestamp(), to_timestamp(&parsed));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_to_chrono_date_time() {
+        use chrono::Timelike;
+
+        let parsed = date_time(b"5 May 2003 18:59:03 -0500").unwrap().1;
+        let chrono_date_time = to_chrono_date_time(&parsed).unwrap();
+        assert_eq!(chrono_date_time.offset().local_minus_utc(), -5 * 3600);
+        assert_eq!(chrono_date_time.hour(), 18);
+        assert_eq!(chrono_date_time.timestamp(), to_timestamp(&parsed));
+    }
 }